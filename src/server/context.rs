@@ -0,0 +1,37 @@
+use std::net::SocketAddr;
+
+#[cfg(feature = "tls")]
+use tokio_rustls::rustls::pki_types::CertificateDer;
+
+/// Information about an accepted connection, handed to [`super::service::NewService::new_service`]
+/// so a service can make per-client decisions (access control, routing, ...)
+/// without having to dig it out of the transport itself.
+#[derive(Debug, Clone)]
+pub struct Context {
+    /// The address of the connected peer.
+    pub socket_addr: SocketAddr,
+
+    /// The peer's verified certificate chain, present only for a Modbus/TCP
+    /// Security (TLS) connection whose client authenticated with mutual TLS.
+    #[cfg(feature = "tls")]
+    pub peer_certificates: Option<Vec<CertificateDer<'static>>>,
+}
+
+impl Context {
+    pub(crate) fn new(socket_addr: SocketAddr) -> Self {
+        Self {
+            socket_addr,
+            #[cfg(feature = "tls")]
+            peer_certificates: None,
+        }
+    }
+
+    #[cfg(feature = "tls")]
+    pub(crate) fn with_peer_certificates(
+        mut self,
+        peer_certificates: Option<Vec<CertificateDer<'static>>>,
+    ) -> Self {
+        self.peer_certificates = peer_certificates;
+        self
+    }
+}
@@ -0,0 +1,42 @@
+use std::{io, net::SocketAddr};
+
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
+};
+
+/// Abstracts over the transport a [`crate::server::tcp::Server`] accepts
+/// connections on, following the same shape as axum's `Listener` trait.
+///
+/// Implementing this for a new transport (TLS, a Unix domain socket, an
+/// in-memory duplex pipe for tests, ...) lets it be driven by the existing
+/// Modbus request/response loop without touching the loop itself.
+pub trait Listener: Send + 'static {
+    /// The I/O type produced for each accepted connection.
+    type Io: AsyncRead + AsyncWrite + Send + Unpin + 'static;
+
+    /// Accept a single incoming connection, yielding its I/O handle and the
+    /// address of the remote peer.
+    fn accept(&mut self) -> impl std::future::Future<Output = io::Result<(Self::Io, SocketAddr)>> + Send;
+}
+
+impl Listener for TcpListener {
+    type Io = tokio::net::TcpStream;
+
+    async fn accept(&mut self) -> io::Result<(Self::Io, SocketAddr)> {
+        TcpListener::accept(self).await
+    }
+}
+
+#[cfg(unix)]
+impl Listener for tokio::net::UnixListener {
+    type Io = tokio::net::UnixStream;
+
+    async fn accept(&mut self) -> io::Result<(Self::Io, SocketAddr)> {
+        let (stream, _) = tokio::net::UnixListener::accept(self).await?;
+        // Unix domain sockets have no meaningful `SocketAddr`, so peers are
+        // reported with the unspecified IPv4 address.
+        let addr = SocketAddr::from(([0, 0, 0, 0], 0));
+        Ok((stream, addr))
+    }
+}
@@ -0,0 +1,17 @@
+//! Modbus server support: accepting connections and dispatching requests to
+//! an application-provided [`Service`].
+
+pub mod context;
+pub mod listener;
+pub mod router;
+pub mod service;
+pub mod tcp;
+
+pub use context::Context;
+pub use listener::Listener;
+pub use router::Router;
+pub use service::{NewService, Service};
+pub use tcp::Server;
+
+#[cfg(unix)]
+pub use tcp::{serve_unix, serve_unix_async, serve_unix_until};
@@ -0,0 +1,160 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    io::{self, Error},
+    pin::Pin,
+    sync::Arc,
+};
+
+use crate::{
+    frame::{ExceptionCode, Request, Response},
+    server::{
+        context::Context,
+        service::{NewService, Service},
+    },
+};
+
+type BoxedResponseFuture = Pin<Box<dyn Future<Output = Result<Response, Error>> + Send>>;
+
+/// A type-erased per-unit [`Service`], as stored in a [`Router`].
+trait UnitService: Send + Sync {
+    fn call_unit(&self, req: Request, unit_id: u8) -> BoxedResponseFuture;
+}
+
+impl<S> UnitService for S
+where
+    S: Service<Request = Request, Response = Response, Error = Error> + Send + Sync,
+    S::Future: Send + 'static,
+{
+    fn call_unit(&self, req: Request, unit_id: u8) -> BoxedResponseFuture {
+        Box::pin(Service::call(self, req, unit_id))
+    }
+}
+
+/// A Modbus gateway that dispatches each request to a backend service chosen
+/// by the `unit_id` carried in its MBAP header, fronting several logical
+/// devices (e.g. serial/RTU slaves) behind a single TCP endpoint.
+///
+/// Requests addressed to a `unit_id` with no registered service are answered
+/// with a Modbus gateway exception rather than being silently dropped.
+#[derive(Clone)]
+pub struct Router {
+    units: HashMap<u8, Arc<dyn UnitService>>,
+}
+
+impl Router {
+    pub fn new() -> Self {
+        Self {
+            units: HashMap::new(),
+        }
+    }
+
+    /// Register `service` to handle every request addressed to `unit_id`.
+    pub fn route<S>(mut self, unit_id: u8, service: S) -> Self
+    where
+        S: Service<Request = Request, Response = Response, Error = Error> + Send + Sync + 'static,
+        S::Future: Send + 'static,
+    {
+        self.units.insert(unit_id, Arc::new(service));
+        self
+    }
+}
+
+impl Default for Router {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Service for Router {
+    type Request = Request;
+    type Response = Response;
+    type Error = Error;
+    type Future = BoxedResponseFuture;
+
+    fn call(&self, req: Self::Request, unit_id: u8) -> Self::Future {
+        if let Some(service) = self.units.get(&unit_id) {
+            return service.call_unit(req, unit_id);
+        }
+
+        // No backend registered for this unit: answer with the Modbus
+        // exception a gateway would return for an unknown unit, instead of
+        // an `Err` that would abort the whole connection with no PDU sent.
+        Box::pin(async move {
+            Ok(Response::Exception(
+                ExceptionCode::GatewayTargetDeviceFailedToRespond,
+            ))
+        })
+    }
+}
+
+impl NewService for Router {
+    type Request = Request;
+    type Response = Response;
+    type Error = Error;
+    type Instance = Router;
+
+    fn new_service(&self, _context: Context) -> io::Result<Self::Instance> {
+        Ok(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::future;
+
+    #[derive(Clone)]
+    struct DummyService {
+        response: Response,
+    }
+
+    impl Service for DummyService {
+        type Request = Request;
+        type Response = Response;
+        type Error = Error;
+        type Future = future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn call(&self, _: Self::Request, _unit_id: u8) -> Self::Future {
+            future::ready(Ok(self.response.clone()))
+        }
+    }
+
+    #[tokio::test]
+    async fn routes_to_the_registered_unit() {
+        let router = Router::new().route(
+            1,
+            DummyService {
+                response: Response::ReadInputRegisters(vec![0x33]),
+            },
+        );
+
+        let response = router
+            .call(Request::ReadInputRegisters(0, 1), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(response, Response::ReadInputRegisters(vec![0x33]));
+    }
+
+    #[tokio::test]
+    async fn answers_an_unrouted_unit_with_a_gateway_exception() {
+        let router = Router::new().route(
+            1,
+            DummyService {
+                response: Response::ReadInputRegisters(vec![0x33]),
+            },
+        );
+
+        let response = router
+            .call(Request::ReadInputRegisters(0, 1), 2)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response,
+            Response::Exception(ExceptionCode::GatewayTargetDeviceFailedToRespond)
+        );
+    }
+}
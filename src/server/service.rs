@@ -0,0 +1,26 @@
+use std::{future::Future, io};
+
+use super::context::Context;
+
+/// Creates a fresh [`Service`] instance to serve a single connection.
+pub trait NewService {
+    type Request;
+    type Response;
+    type Error;
+    type Instance: Service<Request = Self::Request, Response = Self::Response, Error = Self::Error>;
+
+    /// Create a [`Service`] for a newly accepted connection, given its
+    /// [`Context`] (peer address and, for TLS, verified client certificate).
+    fn new_service(&self, context: Context) -> io::Result<Self::Instance>;
+}
+
+/// Handles a single Modbus request and returns a response.
+pub trait Service {
+    type Request;
+    type Response;
+    type Error;
+    type Future: Future<Output = Result<Self::Response, Self::Error>>;
+
+    /// Handle `req`, addressed to the given Modbus `unit_id`.
+    fn call(&self, req: Self::Request, unit_id: u8) -> Self::Future;
+}
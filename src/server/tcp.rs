@@ -1,7 +1,11 @@
 use crate::{
     codec,
     frame::*,
-    server::service::{NewService, Service},
+    server::{
+        context::Context,
+        listener::Listener,
+        service::{NewService, Service},
+    },
 };
 
 use futures::{self, future, select, Future};
@@ -12,14 +16,42 @@ use std::{
     io::{self, Error},
     net::SocketAddr,
     sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
+    sync::watch,
+    task::JoinSet,
 };
-use tokio::net::{TcpListener, TcpStream};
 use tokio_util::codec::Framed;
 
+#[cfg(feature = "tls")]
+use tokio::net::TcpStream;
+#[cfg(feature = "tls")]
+use tokio_rustls::{rustls::ServerConfig as RustlsServerConfig, TlsAcceptor};
+
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use tokio::net::UnixListener;
+
+/// The leading byte of a TLS handshake record (`ContentType::Handshake`),
+/// used to distinguish a Modbus/TCP Security client from a plaintext one.
+#[cfg(feature = "tls")]
+const TLS_HANDSHAKE_BYTE: u8 = 0x16;
+
+/// The `legacy_record_version` major byte every TLS record header carries
+/// right after `TLS_HANDSHAKE_BYTE`, checked alongside it below so a stray
+/// Modbus/TCP transaction ID whose high byte is `0x16` isn't mistaken for TLS.
+#[cfg(feature = "tls")]
+const TLS_MAJOR_VERSION_BYTE: u8 = 0x03;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Server {
     socket_addr: SocketAddr,
     threads: Option<usize>,
+    shutdown_timeout: Option<Duration>,
 }
 
 impl Server {
@@ -28,6 +60,7 @@ impl Server {
         Self {
             socket_addr,
             threads: None,
+            shutdown_timeout: None,
         }
     }
 
@@ -37,6 +70,19 @@ impl Server {
         self
     }
 
+    /// Bound the time graceful shutdown may spend draining in-flight
+    /// connections before it gives up and returns anyway (optional; with no
+    /// timeout, shutdown waits for every connection to finish on its own).
+    pub fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = Some(timeout);
+        self
+    }
+
+    /// Bind the configured address, shared by every `serve*` entry point.
+    fn listener(&self) -> io::Result<TcpListener> {
+        listener(self.socket_addr, self.threads.unwrap_or(1))
+    }
+
     /// Start a Modbus TCP server that blocks the current thread.
     pub fn serve<S>(self, service: S)
     where
@@ -59,23 +105,187 @@ impl Server {
         S::Error: Into<Error>,
         S::Instance: Send + Sync + 'static,
     {
-        let mut server = Server::new(self.socket_addr);
-        if let Some(threads) = self.threads {
-            server = server.threads(threads);
+        let shutdown_timeout = self.shutdown_timeout;
+        // The listener is bound lazily, inside the runtime `serve_until`
+        // builds below: `TcpListener::from_std` requires a reactor to already
+        // be running, which isn't the case yet on this thread.
+        if let Err(e) = serve_until(
+            move || self.listener(),
+            service,
+            shutdown_signal,
+            shutdown_timeout,
+        ) {
+            error!("error: {}", e);
         }
-        serve_until(
-            server.socket_addr,
-            server.threads.unwrap_or(1),
+    }
+
+    /// Run the Modbus TCP accept/request-response loop on the caller's
+    /// existing tokio runtime, until `shutdown_signal` resolves.
+    ///
+    /// Unlike [`Server::serve`]/[`Server::serve_until`], this builds no
+    /// runtime of its own, so it can be `tokio::spawn`ed or `.await`ed
+    /// directly by an application that already owns one.
+    pub async fn serve_async<S, Sd>(self, service: S, shutdown_signal: Sd) -> io::Result<()>
+    where
+        S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+        Sd: Future<Output = ()> + Sync + Send + Unpin + 'static,
+        S::Request: From<Request>,
+        S::Response: Into<Response>,
+        S::Error: Into<Error>,
+        S::Instance: Send + Sync + 'static,
+    {
+        let listener = self.listener()?;
+        serve_listener(listener, service, shutdown_signal, self.shutdown_timeout).await
+    }
+
+    /// Start a Modbus/TCP Security (TLS) server that blocks the current thread.
+    ///
+    /// Every accepted connection is peeked to tell a TLS handshake apart from
+    /// plaintext Modbus/TCP, so the same listener can serve both legacy and
+    /// secured clients side by side.
+    #[cfg(feature = "tls")]
+    pub fn serve_tls<S>(self, service: S, tls_config: Arc<RustlsServerConfig>)
+    where
+        S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+        S::Request: From<Request>,
+        S::Response: Into<Response>,
+        S::Error: Into<Error>,
+        S::Instance: Send + Sync + 'static,
+    {
+        self.serve_tls_until(service, tls_config, future::pending());
+    }
+
+    /// Start a Modbus/TCP Security (TLS) server that blocks the current thread.
+    #[cfg(feature = "tls")]
+    pub fn serve_tls_until<S, Sd>(
+        self,
+        service: S,
+        tls_config: Arc<RustlsServerConfig>,
+        shutdown_signal: Sd,
+    ) where
+        S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+        Sd: Future<Output = ()> + Sync + Send + Unpin + 'static,
+        S::Request: From<Request>,
+        S::Response: Into<Response>,
+        S::Error: Into<Error>,
+        S::Instance: Send + Sync + 'static,
+    {
+        let shutdown_timeout = self.shutdown_timeout;
+        if let Err(e) = serve_tls_until(
+            move || self.listener(),
             service,
+            tls_config,
             shutdown_signal,
-        );
+            shutdown_timeout,
+        ) {
+            error!("error: {}", e);
+        }
+    }
+
+    /// Run the Modbus/TCP Security (TLS) accept/request-response loop on the
+    /// caller's existing tokio runtime, until `shutdown_signal` resolves. See
+    /// [`Server::serve_async`] for why this exists alongside `serve_tls_until`.
+    #[cfg(feature = "tls")]
+    pub async fn serve_tls_async<S, Sd>(
+        self,
+        service: S,
+        tls_config: Arc<RustlsServerConfig>,
+        shutdown_signal: Sd,
+    ) -> io::Result<()>
+    where
+        S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+        Sd: Future<Output = ()> + Sync + Send + Unpin + 'static,
+        S::Request: From<Request>,
+        S::Response: Into<Response>,
+        S::Error: Into<Error>,
+        S::Instance: Send + Sync + 'static,
+    {
+        let listener = self.listener()?;
+        serve_tls_listener(
+            listener,
+            service,
+            tls_config,
+            shutdown_signal,
+            self.shutdown_timeout,
+        )
+        .await
     }
 }
 
-/// Will start a TCP listener and will serve data with service provided
-/// until shutdown signal will be triggered in shutdown_signal future
-fn serve_until<S, Sd>(addr: SocketAddr, workers: usize, new_service: S, shutdown_signal: Sd)
+/// Start a Modbus TCP server, bound to a Unix domain socket instead of a TCP
+/// port, that blocks the current thread. Useful for local inter-process
+/// Modbus gateways that don't need (or want) to expose a network port.
+#[cfg(unix)]
+pub fn serve_unix<S>(path: impl AsRef<Path> + Send + 'static, service: S) -> io::Result<()>
 where
+    S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+    S::Request: From<Request>,
+    S::Response: Into<Response>,
+    S::Error: Into<Error>,
+    S::Instance: Send + Sync + 'static,
+{
+    serve_unix_until(path, service, future::pending())
+}
+
+/// Start a Modbus TCP server, bound to a Unix domain socket, that blocks the
+/// current thread until the shutdown signal fires.
+#[cfg(unix)]
+pub fn serve_unix_until<S, Sd>(
+    path: impl AsRef<Path> + Send + 'static,
+    service: S,
+    shutdown_signal: Sd,
+) -> io::Result<()>
+where
+    S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+    Sd: Future<Output = ()> + Sync + Send + Unpin + 'static,
+    S::Request: From<Request>,
+    S::Response: Into<Response>,
+    S::Error: Into<Error>,
+    S::Instance: Send + Sync + 'static,
+{
+    // Deferred to inside the runtime `serve_until` builds, same as the TCP
+    // listener: `UnixListener::bind` also needs a reactor already running.
+    serve_until(move || UnixListener::bind(path), service, shutdown_signal, None)
+}
+
+/// Run the Modbus/Unix accept/request-response loop on the caller's existing
+/// tokio runtime, until `shutdown_signal` resolves. See
+/// [`Server::serve_async`] for why this exists alongside `serve_unix_until`.
+#[cfg(unix)]
+pub async fn serve_unix_async<S, Sd>(
+    path: impl AsRef<Path>,
+    service: S,
+    shutdown_signal: Sd,
+) -> io::Result<()>
+where
+    S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+    Sd: Future<Output = ()> + Sync + Send + Unpin + 'static,
+    S::Request: From<Request>,
+    S::Response: Into<Response>,
+    S::Error: Into<Error>,
+    S::Instance: Send + Sync + 'static,
+{
+    let listener = UnixListener::bind(path)?;
+    serve_listener(listener, service, shutdown_signal, None).await
+}
+
+/// Will accept connections from the listener returned by `make_listener` and
+/// will serve data with the service provided until the shutdown signal is
+/// triggered.
+///
+/// `make_listener` isn't called until the runtime built here is already
+/// driving it, so it's free to construct anything (a [`TcpListener`] bound
+/// via `TcpListener::from_std`, a `UnixListener::bind`, ...) that only works
+/// inside a reactor.
+fn serve_until<F, L, S, Sd>(
+    make_listener: F,
+    new_service: S,
+    shutdown_signal: Sd,
+    shutdown_timeout: Option<Duration>,
+) -> io::Result<()>
+where
+    F: FnOnce() -> io::Result<L> + Send + 'static,
+    L: Listener,
     S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
     S::Error: Into<Error>,
     S::Instance: 'static + Send + Sync,
@@ -83,27 +293,54 @@ where
 {
     let rt = tokio::runtime::Builder::new_multi_thread()
         .enable_io()
-        .build()
-        .unwrap();
+        .build()?;
+
+    rt.block_on(async move {
+        let listener = make_listener()?;
+        serve_listener(listener, new_service, shutdown_signal, shutdown_timeout).await
+    })
+}
 
+/// The accept loop shared by the blocking `serve_until` and the runtime-less
+/// [`Server::serve_async`]: no runtime is constructed here, so it can be
+/// `.await`ed directly on whatever runtime the caller is already running.
+///
+/// On shutdown, new connections stop being accepted but every in-flight
+/// connection is allowed to finish its current request/response cycle,
+/// bounded by `shutdown_timeout` if one is given.
+pub async fn serve_listener<L, S, Sd>(
+    listener: L,
+    new_service: S,
+    shutdown_signal: Sd,
+    shutdown_timeout: Option<Duration>,
+) -> io::Result<()>
+where
+    L: Listener,
+    S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+    S::Error: Into<Error>,
+    S::Instance: 'static + Send + Sync,
+    Sd: Future<Output = ()> + Unpin + Send + Sync + 'static,
+{
     let new_service = Arc::new(new_service);
+    let (conn_shutdown_tx, conn_shutdown_rx) = watch::channel(false);
+    let mut connections = JoinSet::new();
 
-    let server = async {
-        let listener = listener(addr, workers).unwrap();
+    let accept_loop = async {
+        let mut listener = listener;
 
         loop {
-            let (stream, _) = listener.accept().await?;
-            let framed = Framed::new(stream, codec::tcp::ServerCodec::default());
+            let (io, peer_addr) = listener.accept().await?;
+            let framed = Framed::new(io, codec::tcp::ServerCodec::default());
 
             let new_service = new_service.clone();
-            tokio::spawn(Box::pin(async move {
-                let service = new_service.new_service().unwrap();
-                let future = process(framed, service);
-
-                if let Err(err) = future.await {
+            let conn_shutdown_rx = conn_shutdown_rx.clone();
+            connections.spawn(async move {
+                let context = Context::new(peer_addr);
+                let service = new_service.new_service(context).unwrap();
+                if let Err(err) = process(framed, service, conn_shutdown_rx).await {
                     eprintln!("{:?}", err);
                 }
-            }));
+            });
         }
 
         // the only way found to specify the "task" future error type
@@ -111,41 +348,221 @@ where
         io::Result::<()>::Ok(())
     };
 
-    let mut server = Box::pin(server.fuse());
+    let mut accept_loop = Box::pin(accept_loop.fuse());
     let mut shutdown_signal = shutdown_signal.fuse();
 
-    let task = async {
-        select! {
-            res = server => if let Err(e) = res { error!("error: {}", e) },
-            _ = shutdown_signal => trace!("Shutdown signal received")
+    let result = select! {
+        res = accept_loop => res,
+        _ = shutdown_signal => { trace!("Shutdown signal received, draining connections"); Ok(()) }
+    };
+
+    // Dropping the accept loop releases its borrow of `connections`, and
+    // stops accepting new clients before we start waiting on the existing
+    // ones to finish.
+    drop(accept_loop);
+    let _ = conn_shutdown_tx.send(true);
+    drain(connections, shutdown_timeout).await;
+
+    result
+}
+
+/// Await every still-running connection task, giving up after `timeout` if
+/// one is given.
+async fn drain(mut connections: JoinSet<()>, timeout: Option<Duration>) {
+    let join_all = async { while connections.join_next().await.is_some() {} };
+    match timeout {
+        Some(timeout) => {
+            if tokio::time::timeout(timeout, join_all).await.is_err() {
+                trace!("Shutdown timeout elapsed with connections still draining");
+            }
+        }
+        None => join_all.await,
+    }
+}
+
+/// Will start a TCP listener and will serve Modbus/TCP Security (TLS) clients,
+/// falling back to plaintext Modbus/TCP for connections that don't open with
+/// a TLS handshake, until the shutdown signal fires.
+#[cfg(feature = "tls")]
+fn serve_tls_until<F, L, S, Sd>(
+    make_listener: F,
+    new_service: S,
+    tls_config: Arc<RustlsServerConfig>,
+    shutdown_signal: Sd,
+    shutdown_timeout: Option<Duration>,
+) -> io::Result<()>
+where
+    F: FnOnce() -> io::Result<L> + Send + 'static,
+    L: Listener<Io = TcpStream>,
+    S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+    S::Error: Into<Error>,
+    S::Instance: 'static + Send + Sync,
+    Sd: Future<Output = ()> + Unpin + Send + Sync + 'static,
+{
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_io()
+        .build()?;
+
+    rt.block_on(async move {
+        let listener = make_listener()?;
+        serve_tls_listener(listener, new_service, tls_config, shutdown_signal, shutdown_timeout).await
+    })
+}
+
+/// The accept loop shared by the blocking `serve_tls_until` and the
+/// runtime-less [`Server::serve_tls_async`].
+#[cfg(feature = "tls")]
+async fn serve_tls_listener<L, S, Sd>(
+    listener: L,
+    new_service: S,
+    tls_config: Arc<RustlsServerConfig>,
+    shutdown_signal: Sd,
+    shutdown_timeout: Option<Duration>,
+) -> io::Result<()>
+where
+    L: Listener<Io = TcpStream>,
+    S: NewService<Request = Request, Response = Response> + Send + Sync + 'static,
+    S::Error: Into<Error>,
+    S::Instance: 'static + Send + Sync,
+    Sd: Future<Output = ()> + Unpin + Send + Sync + 'static,
+{
+    let new_service = Arc::new(new_service);
+    let tls_acceptor = TlsAcceptor::from(tls_config);
+    let (conn_shutdown_tx, conn_shutdown_rx) = watch::channel(false);
+    let mut connections = JoinSet::new();
+
+    let accept_loop = async {
+        let mut listener = listener;
+
+        loop {
+            let (stream, peer_addr) = listener.accept().await?;
+            let new_service = new_service.clone();
+            let tls_acceptor = tls_acceptor.clone();
+            let conn_shutdown_rx = conn_shutdown_rx.clone();
+
+            connections.spawn(async move {
+                let is_tls = match peek_is_tls(&stream).await {
+                    Ok(is_tls) => is_tls,
+                    Err(err) => {
+                        eprintln!("{:?}", err);
+                        return;
+                    }
+                };
+
+                let result = if is_tls {
+                    match tls_acceptor.accept(stream).await {
+                        Ok(tls_stream) => {
+                            let peer_certificates = tls_stream
+                                .get_ref()
+                                .1
+                                .peer_certificates()
+                                .map(<[_]>::to_vec);
+                            let context =
+                                Context::new(peer_addr).with_peer_certificates(peer_certificates);
+                            let framed = Framed::new(tls_stream, codec::tcp::ServerCodec::default());
+                            let service = new_service.new_service(context).unwrap();
+                            process(framed, service, conn_shutdown_rx).await
+                        }
+                        Err(err) => Err(err),
+                    }
+                } else {
+                    let context = Context::new(peer_addr);
+                    let framed = Framed::new(stream, codec::tcp::ServerCodec::default());
+                    let service = new_service.new_service(context).unwrap();
+                    process(framed, service, conn_shutdown_rx).await
+                };
+
+                if let Err(err) = result {
+                    eprintln!("{:?}", err);
+                }
+            });
         }
+
+        // the only way found to specify the "task" future error type
+        #[allow(unreachable_code)]
+        io::Result::<()>::Ok(())
+    };
+
+    let mut accept_loop = Box::pin(accept_loop.fuse());
+    let mut shutdown_signal = shutdown_signal.fuse();
+
+    let result = select! {
+        res = accept_loop => res,
+        _ = shutdown_signal => { trace!("Shutdown signal received, draining connections"); Ok(()) }
     };
 
-    rt.block_on(task);
+    drop(accept_loop);
+    let _ = conn_shutdown_tx.send(true);
+    drain(connections, shutdown_timeout).await;
+
+    result
+}
+
+/// Peek at the first bytes of an accepted socket to tell whether the client
+/// is opening a TLS handshake (Modbus/TCP Security) or speaking plain
+/// Modbus/TCP.
+///
+/// The leading byte alone isn't enough: `0x16` is just as valid as the high
+/// byte of a plaintext Modbus/TCP transaction ID. So this also checks the
+/// next byte against the TLS record's `legacy_record_version` major version,
+/// and cross-checks against the MBAP header's protocol ID (bytes 2-3), which
+/// is always `0x0000` for real Modbus/TCP and vanishingly unlikely to line up
+/// by chance with a genuine TLS record header.
+#[cfg(feature = "tls")]
+async fn peek_is_tls(stream: &TcpStream) -> io::Result<bool> {
+    let mut buf = [0u8; 4];
+    let n = stream.peek(&mut buf).await?;
+    if n < 2 {
+        return Ok(false);
+    }
+
+    let looks_like_tls_record = buf[0] == TLS_HANDSHAKE_BYTE && buf[1] == TLS_MAJOR_VERSION_BYTE;
+    let looks_like_mbap = n >= 4 && buf[2] == 0 && buf[3] == 0;
+    Ok(looks_like_tls_record && !looks_like_mbap)
 }
 
-/// The request-response loop spawned by serve_until for each client
-async fn process<S>(
-    framed: Framed<TcpStream, codec::tcp::ServerCodec>,
+/// The request-response loop spawned by `serve_listener` and
+/// `serve_tls_listener` for each client.
+///
+/// `shutdown` is checked at each loop boundary so that a graceful shutdown
+/// lets the current request/response cycle finish instead of cutting it off
+/// mid-exchange.
+async fn process<S, Io>(
+    framed: Framed<Io, codec::tcp::ServerCodec>,
     service: S,
+    mut shutdown: watch::Receiver<bool>,
 ) -> io::Result<()>
 where
     S: Service<Request = Request, Response = Response> + Send + Sync + 'static,
     S::Error: Into<Error>,
+    Io: AsyncRead + AsyncWrite + Send + Unpin + 'static,
 {
     let mut framed = framed;
 
     loop {
-        let request = framed.next().await;
+        if *shutdown.borrow() {
+            break;
+        }
 
-        // tcp socket closed
+        // `watch::Receiver::changed()` returns a `!Unpin` future, which
+        // `futures::select!` can't poll directly, so use `tokio::select!`
+        // here instead (it pins its branches internally).
+        let request = tokio::select! {
+            request = framed.next() => request,
+            _ = shutdown.changed() => break,
+        };
+
+        // connection closed
         if request.is_none() {
             break;
         }
 
         let request = request.unwrap()?;
         let hdr = request.hdr;
-        let response = service.call(request.pdu.0).await.map_err(Into::into)?;
+        let response = service
+            .call(request.pdu.0, hdr.unit_id)
+            .await
+            .map_err(Into::into)?;
 
         framed
             .send(tcp::ResponseAdu {
@@ -190,6 +607,76 @@ mod tests {
 
     use futures::future;
 
+    #[derive(Clone)]
+    struct EchoService;
+
+    impl Service for EchoService {
+        type Request = Request;
+        type Response = Response;
+        type Error = Error;
+        type Future = future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn call(&self, _req: Self::Request, _unit_id: u8) -> Self::Future {
+            future::ready(Ok(Response::ReadInputRegisters(vec![0x00])))
+        }
+    }
+
+    impl NewService for EchoService {
+        type Request = Request;
+        type Response = Response;
+        type Error = Error;
+        type Instance = EchoService;
+
+        fn new_service(&self, _context: Context) -> io::Result<Self::Instance> {
+            Ok(self.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn serve_async_runs_on_the_callers_existing_runtime() {
+        // `#[tokio::test]` already put us inside a runtime; if `serve_async`
+        // tried to build its own (like the blocking `serve_until` does), this
+        // would panic with "Cannot start a runtime from within a runtime".
+        let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let result = Server::new(addr)
+            .serve_async(EchoService, future::ready(()))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn drain_waits_for_an_in_flight_connection_to_finish() {
+        let mut connections = JoinSet::new();
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        connections.spawn(async move {
+            let _ = rx.await;
+        });
+
+        let mut drained = Box::pin(drain(connections, None));
+        // The connection hasn't finished yet, so `drain` shouldn't either.
+        assert!(futures::poll!(&mut drained).is_pending());
+
+        tx.send(()).unwrap();
+        drained.await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn drain_gives_up_once_the_timeout_elapses() {
+        let mut connections = JoinSet::new();
+        connections.spawn(std::future::pending::<()>());
+
+        let timeout = Duration::from_secs(1);
+        let mut drained = Box::pin(drain(connections, Some(timeout)));
+
+        tokio::select! {
+            _ = &mut drained => panic!("drain returned before the timeout elapsed"),
+            _ = tokio::time::sleep(timeout / 2) => {}
+        }
+
+        tokio::time::advance(timeout).await;
+        drained.await;
+    }
+
     #[tokio::test]
     async fn service_wrapper() {
         #[derive(Clone)]
@@ -203,7 +690,7 @@ mod tests {
             type Error = Error;
             type Future = future::Ready<Result<Self::Response, Self::Error>>;
 
-            fn call(&self, _: Self::Request) -> Self::Future {
+            fn call(&self, _: Self::Request, _unit_id: u8) -> Self::Future {
                 future::ready(Ok(self.response.clone()))
             }
         }
@@ -213,7 +700,7 @@ mod tests {
         };
 
         let pdu = Request::ReadInputRegisters(0, 1);
-        let rsp_adu = service.call(pdu).await.unwrap();
+        let rsp_adu = service.call(pdu, 1).await.unwrap();
 
         assert_eq!(rsp_adu, service.response);
     }